@@ -1,27 +1,38 @@
 use crate::handlers::process::{do_process_command, fetch_process_result};
-use crate::handlers::types::{ApiCommand, ApiCommandResult, CompileResponse};
-use crate::utils::lib::{get_file_ext, get_file_path, CAIRO_COMPILERS_DIR, SIERRA_ROOT};
+use crate::handlers::types::{
+    ApiCommand, ApiCommandResult, ArtifactsResponse, CompileResponse, ContractArtifact,
+    Diagnostic, DiagnosticSeverity, Span,
+};
+use crate::utils::lib::{
+    get_file_ext, get_file_path, get_project_path, CAIRO_COMPILERS_DIR, SIERRA_ROOT,
+};
 use crate::worker::WorkerEngine;
 use rocket::fs::NamedFile;
 use rocket::serde::json;
 use rocket::serde::json::Json;
 use rocket::tokio::fs;
 use rocket::State;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 use tracing::{debug, instrument};
 
 #[instrument]
-#[get("/compile-to-sierra/<version>/<remix_file_path..>")]
-pub async fn compile_to_sierra(version: String, remix_file_path: PathBuf) -> Json<CompileResponse> {
+#[get("/compile-to-sierra/<version>/<remix_file_path..>?<contract_path>")]
+pub async fn compile_to_sierra(
+    version: String,
+    remix_file_path: PathBuf,
+    contract_path: Option<String>,
+) -> Json<CompileResponse> {
     info!("/compile-to-sierra");
 
-    let res = do_compile_to_sierra(version.clone(), remix_file_path).await;
+    let res = do_compile_to_sierra(version.clone(), remix_file_path, contract_path).await;
 
     match res {
         Ok(res) => res,
         Err(e) => Json(CompileResponse {
             file_content: "".to_string(),
+            diagnostics: vec![],
             message: e,
             status: "CompilationFailed".to_string(),
             cairo_version: version,
@@ -30,10 +41,11 @@ pub async fn compile_to_sierra(version: String, remix_file_path: PathBuf) -> Jso
 }
 
 #[instrument]
-#[get("/compile-to-sierra-async/<version>/<remix_file_path..>")]
+#[get("/compile-to-sierra-async/<version>/<remix_file_path..>?<contract_path>")]
 pub async fn compile_to_siera_async(
     version: String,
     remix_file_path: PathBuf,
+    contract_path: Option<String>,
     engine: &State<WorkerEngine>,
 ) -> String {
     info!("/compile-to-sierra-async");
@@ -41,6 +53,7 @@ pub async fn compile_to_siera_async(
         ApiCommand::SierraCompile {
             version,
             remix_file_path,
+            contract_path,
         },
         engine,
     )
@@ -56,17 +69,71 @@ pub async fn get_siera_compile_result(process_id: String, engine: &State<WorkerE
     })
 }
 
+#[instrument]
+#[get("/scarb-build/<version>/<remix_file_path..>?<contract_path>")]
+pub async fn scarb_build(
+    version: String,
+    remix_file_path: PathBuf,
+    contract_path: Option<String>,
+) -> Json<ArtifactsResponse> {
+    info!("/scarb-build");
+
+    let res = do_scarb_build(version.clone(), remix_file_path, contract_path).await;
+
+    match res {
+        Ok(res) => res,
+        Err(e) => Json(ArtifactsResponse {
+            contracts: HashMap::new(),
+            diagnostics: vec![],
+            message: e,
+            status: "CompilationFailed".to_string(),
+            cairo_version: version,
+        }),
+    }
+}
+
+#[instrument]
+#[get("/scarb-build-async/<version>/<remix_file_path..>?<contract_path>")]
+pub async fn scarb_build_async(
+    version: String,
+    remix_file_path: PathBuf,
+    contract_path: Option<String>,
+    engine: &State<WorkerEngine>,
+) -> String {
+    info!("/scarb-build-async");
+    do_process_command(
+        ApiCommand::ScarbBuild {
+            version,
+            remix_file_path,
+            contract_path,
+        },
+        engine,
+    )
+}
+
+#[instrument]
+#[get("/scarb-build-result/<process_id>")]
+pub async fn get_scarb_build_result(process_id: String, engine: &State<WorkerEngine>) -> String {
+    info!("/scarb-build-result");
+    fetch_process_result(process_id, engine, |result| match result {
+        ApiCommandResult::ScarbBuild(build_result) => json::to_string(&build_result).unwrap(),
+        _ => String::from("Result not available"),
+    })
+}
+
 /// Compile a given file to Sierra bytecode
 ///
 pub async fn do_compile_to_sierra(
     version: String,
     remix_file_path: PathBuf,
+    contract_path: Option<String>,
 ) -> Result<Json<CompileResponse>, String> {
     let remix_file_path = match remix_file_path.to_str() {
         Some(path) => path.to_string(),
         None => {
             return Ok(Json(CompileResponse {
                 file_content: "".to_string(),
+                diagnostics: vec![],
                 message: "File path not found".to_string(),
                 status: "FileNotFound".to_string(),
                 cairo_version: version,
@@ -83,6 +150,7 @@ pub async fn do_compile_to_sierra(
             debug!("LOG: File extension not supported");
             return Ok(Json(CompileResponse {
                 file_content: "".to_string(),
+                diagnostics: vec![],
                 message: "File extension not supported".to_string(),
                 status: "FileExtensionNotSupported".to_string(),
                 cairo_version: version,
@@ -90,6 +158,22 @@ pub async fn do_compile_to_sierra(
         }
     }
 
+    // A single-file compile only ever produces one contract, named after the
+    // file itself. If the caller asked for a different one, there's nothing
+    // to build for them.
+    if let Some(wanted) = &contract_path {
+        let produced = contract_key_from_remix_path(&remix_file_path);
+        if wanted != &produced {
+            return Ok(Json(CompileResponse {
+                file_content: "".to_string(),
+                diagnostics: vec![],
+                message: format!("Contract '{}' not found, only '{}' was produced", wanted, produced),
+                status: "ContractNotFound".to_string(),
+                cairo_version: version,
+            }));
+        }
+    }
+
     let file_path = get_file_path(&remix_file_path);
 
     let sierra_remix_path = remix_file_path.replace(&get_file_ext(&remix_file_path), "sierra");
@@ -139,6 +223,9 @@ pub async fn do_compile_to_sierra(
 
     let output = result.wait_with_output().expect("Failed to wait on child");
 
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let diagnostics = parse_diagnostics(&stderr, &file_path, &remix_file_path);
+
     Ok(Json(CompileResponse {
         file_content: match NamedFile::open(&sierra_path).await.ok() {
             Some(file) => match file.path().to_str() {
@@ -150,8 +237,8 @@ pub async fn do_compile_to_sierra(
             },
             None => "".to_string(),
         },
-        message: String::from_utf8(output.stderr)
-            .unwrap()
+        diagnostics,
+        message: stderr
             .replace(&file_path.to_str().unwrap().to_string(), &remix_file_path)
             .replace(
                 &sierra_path.to_str().unwrap().to_string(),
@@ -165,3 +252,444 @@ pub async fn do_compile_to_sierra(
         cairo_version: version,
     }))
 }
+
+/// Parses the Cairo compiler's textual diagnostics into structured
+/// `Diagnostic`s, so the remix_file_path remapping happens once on the
+/// structured `remix_file_path` field rather than via `.replace()` calls
+/// sprinkled through free-text messages.
+///
+/// Expects the compiler's usual `error: <message>` / `warning: <message>`
+/// header line followed by a `--> <file>:<line>:<col>` location line, with
+/// an optional caret (`^^^^`) underline line giving the span's width.
+fn parse_diagnostics(stderr: &str, server_file_path: &Path, remix_file_path: &str) -> Vec<Diagnostic> {
+    let server_file_path = match server_file_path.to_str() {
+        Some(path) => path,
+        None => return Vec::new(),
+    };
+
+    let mut diagnostics = Vec::new();
+    let mut lines = stderr.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let (severity, message) = if let Some(message) = line.strip_prefix("error: ") {
+            (DiagnosticSeverity::Error, message.to_string())
+        } else if let Some(message) = line.strip_prefix("warning: ") {
+            (DiagnosticSeverity::Warning, message.to_string())
+        } else {
+            continue;
+        };
+
+        let mut span = None;
+        if let Some(location) = lines.peek().and_then(|l| l.trim().strip_prefix("--> ")) {
+            if let Some(position) = location.strip_prefix(server_file_path) {
+                lines.next(); // consume the `--> file:line:col` line
+
+                let mut parts = position.trim_start_matches(':').split(':');
+                if let (Some(line_str), Some(col_str)) = (parts.next(), parts.next()) {
+                    if let (Ok(start_line), Ok(start_col)) =
+                        (line_str.parse::<u32>(), col_str.parse::<u32>())
+                    {
+                        // The compiler echoes the offending source line
+                        // before underlining it with carets, so that line
+                        // has to be consumed (not just peeked past) before
+                        // the caret line comes up next.
+                        if let Some(source_line) = lines.peek() {
+                            let looks_like_source_line = !source_line.trim().starts_with("error: ")
+                                && !source_line.trim().starts_with("warning: ")
+                                && !source_line.trim().starts_with("--> ");
+                            if looks_like_source_line {
+                                lines.next();
+                            }
+                        }
+
+                        let caret_width = lines
+                            .peek()
+                            .map(|l| l.trim_start())
+                            .filter(|l| !l.is_empty() && l.chars().all(|c| c == '^'))
+                            .map(|l| l.len() as u32);
+                        if caret_width.is_some() {
+                            lines.next(); // consume the caret underline
+                        }
+
+                        span = Some(Span {
+                            start_line,
+                            start_col,
+                            end_line: start_line,
+                            end_col: start_col + caret_width.unwrap_or(0),
+                        });
+                    }
+                }
+            }
+        }
+
+        diagnostics.push(Diagnostic {
+            severity,
+            message,
+            remix_file_path: remix_file_path.to_string(),
+            span,
+        });
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod diagnostics_tests {
+    use super::*;
+
+    #[test]
+    fn parses_error_with_source_line_and_span() {
+        let stderr = "error: Identifier not found.\n \
+                       --> /srv/contracts/lib.cairo:3:5\n    \
+                       undefined_identifier();\n    \
+                       ^^^^^^^^^^^^^^^^^^^^\n";
+
+        let diagnostics = parse_diagnostics(stderr, Path::new("/srv/contracts/lib.cairo"), "lib.cairo");
+
+        assert_eq!(diagnostics.len(), 1);
+        let diagnostic = &diagnostics[0];
+        assert_eq!(diagnostic.severity, DiagnosticSeverity::Error);
+        assert_eq!(diagnostic.message, "Identifier not found.");
+        assert_eq!(diagnostic.remix_file_path, "lib.cairo");
+
+        let span = diagnostic.span.as_ref().expect("expected a span");
+        assert_eq!(span.start_line, 3);
+        assert_eq!(span.start_col, 5);
+        assert_eq!(span.end_line, 3);
+        assert_eq!(span.end_col, 5 + "^^^^^^^^^^^^^^^^^^^^".len() as u32);
+    }
+
+    #[test]
+    fn parses_multiple_diagnostics_without_cross_contamination() {
+        let stderr = "warning: Unused variable.\n \
+                       --> /srv/contracts/lib.cairo:1:1\n\
+                       let x = 1;\n\
+                       ^\n\
+                       error: Identifier not found.\n \
+                       --> /srv/contracts/lib.cairo:5:9\n    \
+                       bar();\n    \
+                       ^^^\n";
+
+        let diagnostics = parse_diagnostics(stderr, Path::new("/srv/contracts/lib.cairo"), "lib.cairo");
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+        assert_eq!(diagnostics[0].span.as_ref().unwrap().start_line, 1);
+        assert_eq!(diagnostics[1].severity, DiagnosticSeverity::Error);
+        assert_eq!(diagnostics[1].span.as_ref().unwrap().start_line, 5);
+    }
+
+    #[test]
+    fn missing_location_yields_no_span() {
+        let stderr = "warning: unused import\n";
+        let diagnostics = parse_diagnostics(stderr, Path::new("/srv/contracts/lib.cairo"), "lib.cairo");
+
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].span.is_none());
+    }
+}
+
+/// Build a Cairo project located under `SIERRA_ROOT`.
+///
+/// If the project root (the parent directory of `remix_file_path`) contains a
+/// `Scarb.toml`, the build is delegated to `scarb build` and the resulting
+/// Sierra/CASM artifacts are read back from its `target/dev` output
+/// directory, one entry per contract produced, keyed by fully-qualified
+/// contract path. Otherwise this falls back to the bundled
+/// `starknet-compile`, same as `do_compile_to_sierra`, except the whole
+/// project directory is passed instead of a single file so that a bare
+/// `cairo_project.toml` with multiple modules still compiles; in that case a
+/// single contract entry is produced. When `contract_path` is given, only the
+/// matching entry is returned.
+pub async fn do_scarb_build(
+    version: String,
+    remix_file_path: PathBuf,
+    contract_path: Option<String>,
+) -> Result<Json<ArtifactsResponse>, String> {
+    let remix_file_path = match remix_file_path.to_str() {
+        Some(path) => path.to_string(),
+        None => {
+            return Ok(Json(ArtifactsResponse {
+                contracts: HashMap::new(),
+                diagnostics: vec![],
+                message: "File path not found".to_string(),
+                status: "FileNotFound".to_string(),
+                cairo_version: version,
+            }));
+        }
+    };
+
+    let project_path = get_project_path(&remix_file_path);
+    match fs::create_dir_all(&project_path).await {
+        Ok(_) => {
+            debug!("LOG: Created directory: {:?}", project_path);
+        }
+        Err(e) => {
+            debug!("LOG: Error creating directory: {:?}", e);
+        }
+    }
+
+    let scarb_toml = project_path.join("Scarb.toml");
+
+    let (contracts, diagnostics, message, status) = if scarb_toml.exists() {
+        let result = Command::new("scarb")
+            .current_dir(&project_path)
+            .arg("build")
+            .stderr(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("Failed to execute scarb: {:?}", e))?;
+
+        let output = result.wait_with_output().expect("Failed to wait on child");
+        let stderr = String::from_utf8(output.stderr).unwrap();
+
+        // The `target/dev` manifest is only written on a successful build;
+        // on failure there's nothing to collect and `stderr` already holds
+        // the real diagnostic the caller needs.
+        let contracts = if output.status.code() == Some(0) {
+            let target_dev = project_path.join("target").join("dev");
+            collect_scarb_artifacts(&target_dev).await?
+        } else {
+            HashMap::new()
+        };
+
+        let status = match output.status.code() {
+            Some(0) => "Success".to_string(),
+            Some(_) => "CompilationFailed".to_string(),
+            None => "UnknownError".to_string(),
+        };
+
+        // `scarb build`'s diagnostics aren't in the `starknet-compile` text
+        // format `parse_diagnostics` understands, so there's nothing
+        // structured to extract here yet.
+        (contracts, Vec::new(), stderr, status)
+    } else {
+        // No Scarb.toml: fall back to starknet-compile, but hand it the
+        // project directory (which must contain a cairo_project.toml)
+        // instead of a single .cairo file. Only one contract comes out of
+        // this path. Only this branch needs the pinned compiler toolchain,
+        // so the existence check lives here rather than gating Scarb builds
+        // that never touch it.
+        let path_to_cairo_compiler = Path::new(CAIRO_COMPILERS_DIR).join(&version);
+        if !path_to_cairo_compiler.exists() {
+            return Err(format!("Cairo compiler with version {} not found", version));
+        }
+
+        let sierra_remix_path = format!("{}.sierra", remix_file_path.trim_end_matches(".cairo"));
+        let sierra_path = Path::new(SIERRA_ROOT).join(&sierra_remix_path);
+
+        match sierra_path.parent() {
+            Some(parent) => match fs::create_dir_all(parent).await {
+                Ok(_) => {
+                    debug!("LOG: Created directory: {:?}", parent);
+                }
+                Err(e) => {
+                    debug!("LOG: Error creating directory: {:?}", e);
+                }
+            },
+            None => {
+                debug!("LOG: Error creating directory");
+            }
+        }
+
+        let mut compile = Command::new("cargo");
+        compile.current_dir(&path_to_cairo_compiler);
+
+        let result = compile
+            .arg("run")
+            .arg("--release")
+            .arg("--bin")
+            .arg("starknet-compile")
+            .arg("--")
+            .arg(&project_path)
+            .arg(&sierra_path)
+            .stderr(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("Failed to execute starknet-compile: {:?}", e))?;
+
+        debug!("LOG: ran command:{:?}", compile);
+
+        let output = result.wait_with_output().expect("Failed to wait on child");
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        let diagnostics = parse_diagnostics(&stderr, &project_path, &remix_file_path);
+
+        let mut contracts = HashMap::new();
+        contracts.insert(
+            contract_key_from_remix_path(&remix_file_path),
+            ContractArtifact {
+                sierra: fs::read_to_string(&sierra_path).await.unwrap_or_default(),
+                casm: None,
+            },
+        );
+
+        let message = stderr
+            .replace(&project_path.to_str().unwrap().to_string(), &remix_file_path)
+            .replace(
+                &sierra_path.to_str().unwrap().to_string(),
+                &sierra_remix_path,
+            );
+
+        let status = match output.status.code() {
+            Some(0) => "Success".to_string(),
+            Some(_) => "CompilationFailed".to_string(),
+            None => "UnknownError".to_string(),
+        };
+
+        (contracts, diagnostics, message, status)
+    };
+
+    let contracts = match contract_path {
+        Some(wanted) => match contracts.get(&wanted) {
+            Some(artifact) => HashMap::from([(wanted, artifact.clone())]),
+            None => HashMap::new(),
+        },
+        None => contracts,
+    };
+
+    Ok(Json(ArtifactsResponse {
+        contracts,
+        diagnostics,
+        message,
+        status,
+        cairo_version: version,
+    }))
+}
+
+/// Deserializes scarb's own `<package>.starknet_artifacts.json` manifest,
+/// which is the authoritative record of which Sierra/CASM files under
+/// `target/dev` belong to which contract module path — scarb writes it
+/// alongside the artifacts on every `scarb build` of a Starknet package.
+#[derive(Debug, rocket::serde::Deserialize)]
+#[serde(crate = "rocket::serde")]
+struct StarknetArtifactsManifest {
+    contracts: Vec<StarknetArtifactEntry>,
+}
+
+#[derive(Debug, rocket::serde::Deserialize)]
+#[serde(crate = "rocket::serde")]
+struct StarknetArtifactEntry {
+    module_path: String,
+    artifacts: StarknetArtifactFiles,
+}
+
+#[derive(Debug, rocket::serde::Deserialize)]
+#[serde(crate = "rocket::serde")]
+struct StarknetArtifactFiles {
+    sierra: Option<String>,
+    casm: Option<String>,
+}
+
+/// Reads the Sierra and CASM artifacts that `scarb build` produced under
+/// `target_dev`, one entry per contract keyed by its fully-qualified module
+/// path (e.g. `token::myerc20::ERC20`). The mapping comes from scarb's own
+/// `*.starknet_artifacts.json` manifest rather than guessed from file names,
+/// since the artifact file names are not a reversible encoding of the
+/// module path.
+async fn collect_scarb_artifacts(
+    target_dev: &Path,
+) -> Result<HashMap<String, ContractArtifact>, String> {
+    let mut read_dir = fs::read_dir(target_dev)
+        .await
+        .map_err(|e| format!("Failed to read {:?}: {:?}", target_dev, e))?;
+
+    let mut manifest_path = None;
+    while let Ok(Some(entry)) = read_dir.next_entry().await {
+        let path = entry.path();
+        if path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|name| name.ends_with(".starknet_artifacts.json"))
+        {
+            manifest_path = Some(path);
+            break;
+        }
+    }
+
+    let manifest_path = manifest_path
+        .ok_or_else(|| format!("No *.starknet_artifacts.json found in {:?}", target_dev))?;
+    let manifest_raw = fs::read_to_string(&manifest_path)
+        .await
+        .map_err(|e| format!("Failed to read {:?}: {:?}", manifest_path, e))?;
+    let manifest: StarknetArtifactsManifest = json::from_str(&manifest_raw)
+        .map_err(|e| format!("Failed to parse {:?}: {:?}", manifest_path, e))?;
+
+    let mut contracts = HashMap::new();
+    for entry in manifest.contracts {
+        let sierra = match &entry.artifacts.sierra {
+            Some(file_name) => fs::read_to_string(target_dev.join(file_name))
+                .await
+                .unwrap_or_default(),
+            None => String::new(),
+        };
+        let casm = match &entry.artifacts.casm {
+            Some(file_name) => fs::read_to_string(target_dev.join(file_name)).await.ok(),
+            None => None,
+        };
+
+        contracts.insert(entry.module_path, ContractArtifact { sierra, casm });
+    }
+
+    Ok(contracts)
+}
+
+/// Derives the contract key a single-file compile (no Scarb multi-contract
+/// build, so no `*.starknet_artifacts.json` manifest to read) is assumed to
+/// have produced. There is no reliable module path to recover in this
+/// fallback, so the Remix file's own stem is used verbatim rather than
+/// guessed at with a lossy transformation.
+fn contract_key_from_remix_path(remix_file_path: &str) -> String {
+    Path::new(remix_file_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(remix_file_path)
+        .to_string()
+}
+
+#[cfg(test)]
+mod artifacts_tests {
+    use super::*;
+
+    #[test]
+    fn contract_key_from_remix_path_keeps_snake_case_verbatim() {
+        assert_eq!(
+            contract_key_from_remix_path("contracts/simple_account.cairo"),
+            "simple_account"
+        );
+    }
+
+    #[test]
+    fn parses_starknet_artifacts_manifest() {
+        let raw = r#"{
+            "version": 1,
+            "contracts": [
+                {
+                    "module_path": "hello_starknet::hello_starknet::HelloStarknet",
+                    "artifacts": {
+                        "sierra": "hello_starknet_HelloStarknet.sierra.json",
+                        "casm": "hello_starknet_HelloStarknet.casm.json"
+                    }
+                },
+                {
+                    "module_path": "hello_starknet::other::Other",
+                    "artifacts": {
+                        "sierra": "hello_starknet_Other.sierra.json",
+                        "casm": null
+                    }
+                }
+            ]
+        }"#;
+
+        let manifest: StarknetArtifactsManifest = json::from_str(raw).unwrap();
+        assert_eq!(manifest.contracts.len(), 2);
+        assert_eq!(
+            manifest.contracts[0].module_path,
+            "hello_starknet::hello_starknet::HelloStarknet"
+        );
+        assert_eq!(
+            manifest.contracts[0].artifacts.sierra.as_deref(),
+            Some("hello_starknet_HelloStarknet.sierra.json")
+        );
+        assert_eq!(manifest.contracts[1].artifacts.casm, None);
+    }
+}
@@ -0,0 +1,306 @@
+use crate::handlers::compile_sierra::do_scarb_build;
+use crate::handlers::process::{do_process_command, fetch_process_result};
+use crate::handlers::types::{ApiCommand, ApiCommandResult, DetectResponse, Finding, Severity};
+use crate::worker::WorkerEngine;
+use rocket::serde::json;
+use rocket::serde::json::Json;
+use rocket::State;
+use std::path::PathBuf;
+use tracing::instrument;
+
+#[instrument]
+#[get("/detect/<version>/<remix_file_path..>")]
+pub async fn detect(version: String, remix_file_path: PathBuf) -> Json<DetectResponse> {
+    info!("/detect");
+
+    let res = do_detect(version.clone(), remix_file_path).await;
+
+    match res {
+        Ok(res) => res,
+        Err(e) => Json(DetectResponse {
+            findings: vec![],
+            message: e,
+            status: "DetectionFailed".to_string(),
+            cairo_version: version,
+        }),
+    }
+}
+
+#[instrument]
+#[get("/detect-async/<version>/<remix_file_path..>")]
+pub async fn detect_async(
+    version: String,
+    remix_file_path: PathBuf,
+    engine: &State<WorkerEngine>,
+) -> String {
+    info!("/detect-async");
+    do_process_command(
+        ApiCommand::Detect {
+            version,
+            remix_file_path,
+        },
+        engine,
+    )
+}
+
+#[instrument]
+#[get("/detect-result/<process_id>")]
+pub async fn get_detect_result(process_id: String, engine: &State<WorkerEngine>) -> String {
+    info!("/detect-result");
+    fetch_process_result(process_id, engine, |result| match result {
+        ApiCommandResult::Detect(detect_result) => json::to_string(&detect_result).unwrap(),
+        _ => String::from("Result not available"),
+    })
+}
+
+/// Runs the built-in static detectors over every contract produced from
+/// `remix_file_path`.
+///
+/// Supports the same project layouts as compilation: `do_scarb_build`
+/// delegates to `scarb build` when the project has a `Scarb.toml`, and
+/// otherwise falls back to `starknet-compile` against the whole project
+/// directory (so a bare `cairo_project.toml` still works), producing a
+/// single contract entry.
+pub async fn do_detect(
+    version: String,
+    remix_file_path: PathBuf,
+) -> Result<Json<DetectResponse>, String> {
+    let build = do_scarb_build(version.clone(), remix_file_path, None).await?.0;
+    if build.status != "Success" {
+        return Ok(Json(DetectResponse {
+            findings: vec![],
+            message: build.message,
+            status: build.status,
+            cairo_version: version,
+        }));
+    }
+    let contracts = build.contracts;
+
+    let mut findings = Vec::new();
+    for (contract_path, artifact) in &contracts {
+        let functions = iter_functions(&artifact.sierra);
+        findings.extend(detect_unused_call_results(contract_path, &functions));
+        findings.extend(detect_setter_without_write(contract_path, &functions));
+        findings.extend(detect_reentrancy_shape(contract_path, &functions));
+    }
+
+    Ok(Json(DetectResponse {
+        findings,
+        message: "".to_string(),
+        status: "Success".to_string(),
+        cairo_version: version,
+    }))
+}
+
+/// A function declaration line from the Sierra listing's function section,
+/// of the form `<name>@<start_statement_idx>(<params>) -> (<rets>);`.
+struct FunctionDeclaration {
+    name: String,
+    start_statement_idx: usize,
+}
+
+/// Parses a single function declaration line, if `line` looks like one.
+fn parse_function_declaration(line: &str) -> Option<FunctionDeclaration> {
+    let line = line.trim().strip_suffix(';')?;
+    let (head, _params_and_rets) = line.split_once('(')?;
+    let (name, start_statement_idx) = head.split_once('@')?;
+    Some(FunctionDeclaration {
+        name: name.trim().to_string(),
+        start_statement_idx: start_statement_idx.trim().parse().ok()?,
+    })
+}
+
+/// A line belongs to the statement listing (as opposed to the type, libfunc
+/// or function declaration sections) if it has the shape of a Sierra
+/// statement: a leading statement index, e.g. `12 = store_temp<felt252>([3])
+/// -> ([4]);`. Declarations use `name@idx(...)` instead, so checking for the
+/// index-then-`=` prefix keeps the two apart.
+fn is_statement_line(line: &str) -> bool {
+    let trimmed = line.trim();
+    match trimmed.split_once('=') {
+        Some((idx, _)) => !idx.trim().is_empty() && idx.trim().chars().all(|c| c.is_ascii_digit()),
+        None => false,
+    }
+}
+
+/// Splits a Sierra listing into `(function_name, body)` pairs.
+///
+/// `starknet-compile`/`scarb build` text output ends with a function section
+/// where each function is declared on its own line as
+/// `<name>@<start_statement_idx>(<params>) -> (<rets>);`. The statement
+/// listing itself has no per-function markers, so a function's body is
+/// recovered by slicing the statement lines between its declared start index
+/// and the next function's start index (or the end of the listing for the
+/// last function).
+fn iter_functions(sierra: &str) -> Vec<(String, String)> {
+    let statement_lines: Vec<&str> = sierra.lines().filter(|l| is_statement_line(l)).collect();
+
+    let mut declarations: Vec<FunctionDeclaration> = sierra
+        .lines()
+        .filter_map(parse_function_declaration)
+        .collect();
+
+    if declarations.is_empty() {
+        return if sierra.trim().is_empty() {
+            Vec::new()
+        } else {
+            vec![("<unknown>".to_string(), sierra.to_string())]
+        };
+    }
+
+    declarations.sort_by_key(|d| d.start_statement_idx);
+
+    let mut functions = Vec::with_capacity(declarations.len());
+    for (i, decl) in declarations.iter().enumerate() {
+        let end = declarations
+            .get(i + 1)
+            .map(|next| next.start_statement_idx)
+            .unwrap_or(statement_lines.len());
+        let body = statement_lines
+            .get(decl.start_statement_idx..end)
+            .unwrap_or(&[])
+            .join("\n");
+        functions.push((decl.name.clone(), body));
+    }
+
+    functions
+}
+
+/// Flags functions that make an external call but never store or return its
+/// result, which usually means the call was meant to be checked and wasn't.
+fn detect_unused_call_results(contract_path: &str, functions: &[(String, String)]) -> Vec<Finding> {
+    functions
+        .iter()
+        .filter(|(_, body)| body.contains("call_contract_syscall") && !body.contains("store_temp"))
+        .map(|(function, _)| Finding {
+            detector_id: "unused-call-result".to_string(),
+            severity: Severity::Low,
+            title: "External call result is discarded".to_string(),
+            contract_path: contract_path.to_string(),
+            function: function.clone(),
+            message:
+                "An external call's return value does not appear to be stored or propagated."
+                    .to_string(),
+        })
+        .collect()
+}
+
+/// Flags functions named like a setter (`set_*`/`update_*`) that never write
+/// to storage, which usually means the setter is a no-op or the write was
+/// forgotten.
+fn detect_setter_without_write(contract_path: &str, functions: &[(String, String)]) -> Vec<Finding> {
+    functions
+        .iter()
+        .filter(|(function, body)| {
+            let short_name = function.rsplit("::").next().unwrap_or(function);
+            (short_name.starts_with("set_") || short_name.starts_with("update_"))
+                && !body.contains("storage_write")
+        })
+        .map(|(function, _)| Finding {
+            detector_id: "setter-without-write".to_string(),
+            severity: Severity::Medium,
+            title: "Setter-named function never writes storage".to_string(),
+            contract_path: contract_path.to_string(),
+            function: function.clone(),
+            message:
+                "Function name suggests it mutates state but no storage_write appears in its body."
+                    .to_string(),
+        })
+        .collect()
+}
+
+/// Flags functions where an external call textually precedes a storage
+/// write, the classic checks-effects-interactions violation shape.
+fn detect_reentrancy_shape(contract_path: &str, functions: &[(String, String)]) -> Vec<Finding> {
+    functions
+        .iter()
+        .filter(|(_, body)| match (body.find("call_contract_syscall"), body.find("storage_write")) {
+            (Some(call_idx), Some(write_idx)) => call_idx < write_idx,
+            _ => false,
+        })
+        .map(|(function, _)| Finding {
+            detector_id: "reentrancy-shape".to_string(),
+            severity: Severity::High,
+            title: "External call precedes a storage write".to_string(),
+            contract_path: contract_path.to_string(),
+            function: function.clone(),
+            message: "An external call appears before a storage write in the same function; \
+                      this is vulnerable to reentrancy unless checks-effects-interactions is \
+                      otherwise enforced."
+                .to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod detector_tests {
+    use super::*;
+
+    // A small Sierra-like listing exercising all three detectors:
+    // - `withdraw` calls out before writing storage (reentrancy shape, and
+    //   its call result is unused).
+    // - `set_balance` is a setter that writes storage (should not fire).
+    // - `set_owner` is a setter that never writes storage (should fire).
+    const SAMPLE_SIERRA: &str = "\
+type felt252 = felt252;
+libfunc store_temp<felt252> = store_temp<felt252>;
+libfunc call_contract_syscall = call_contract_syscall;
+libfunc storage_write_syscall = storage_write_syscall;
+
+0 = call_contract_syscall([0]) -> ([1]);
+1 = storage_write_syscall([1]) -> ([2]);
+2 = store_temp<felt252>([2]) -> ([3]);
+3 = storage_write_syscall([3]) -> ([4]);
+4 = return([4]) -> ();
+
+withdraw@0(a: felt252) -> (felt252);
+set_balance@2(a: felt252) -> (felt252);
+set_owner@4(a: felt252) -> (felt252);
+";
+
+    #[test]
+    fn iter_functions_recovers_bodies_by_start_index() {
+        let functions = iter_functions(SAMPLE_SIERRA);
+        let names: Vec<&str> = functions.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["withdraw", "set_balance", "set_owner"]);
+
+        let withdraw_body = &functions[0].1;
+        assert!(withdraw_body.contains("call_contract_syscall"));
+        assert!(withdraw_body.contains("storage_write_syscall"));
+        assert!(!withdraw_body.contains("store_temp"));
+
+        let set_balance_body = &functions[1].1;
+        assert!(set_balance_body.contains("store_temp"));
+        assert!(set_balance_body.contains("storage_write_syscall"));
+        assert!(!set_balance_body.contains("call_contract_syscall"));
+    }
+
+    #[test]
+    fn detects_unused_call_result() {
+        let functions = iter_functions(SAMPLE_SIERRA);
+        let findings = detect_unused_call_results("contract", &functions);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].function, "withdraw");
+    }
+
+    #[test]
+    fn detects_setter_without_write_but_not_the_good_setter() {
+        let functions = iter_functions(SAMPLE_SIERRA);
+        let findings = detect_setter_without_write("contract", &functions);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].function, "set_owner");
+    }
+
+    #[test]
+    fn detects_reentrancy_shape() {
+        let functions = iter_functions(SAMPLE_SIERRA);
+        let findings = detect_reentrancy_shape("contract", &functions);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].function, "withdraw");
+    }
+
+    #[test]
+    fn empty_listing_yields_no_functions() {
+        assert!(iter_functions("").is_empty());
+    }
+}
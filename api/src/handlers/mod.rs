@@ -0,0 +1,5 @@
+pub mod compile_sierra;
+pub mod detect;
+pub mod process;
+pub mod run_tests;
+pub mod types;
@@ -0,0 +1,102 @@
+use crate::handlers::compile_sierra::{do_compile_to_sierra, do_scarb_build};
+use crate::handlers::detect::do_detect;
+use crate::handlers::run_tests::do_run_tests;
+use crate::handlers::types::{
+    ApiCommand, ApiCommandResult, ArtifactsResponse, CompileResponse, DetectResponse,
+    RunTestsResponse,
+};
+use crate::worker::WorkerEngine;
+use rocket::State;
+use std::collections::HashMap;
+
+/// Hands `command` off to the `WorkerEngine` and returns the `process_id`
+/// the caller should poll the matching `*-result` route with.
+pub fn do_process_command(command: ApiCommand, engine: &State<WorkerEngine>) -> String {
+    engine.add_command(command)
+}
+
+/// Looks up the result for `process_id` and formats it with `f`, or reports
+/// that the process hasn't finished (or never existed) yet.
+pub fn fetch_process_result<F>(process_id: String, engine: &State<WorkerEngine>, f: F) -> String
+where
+    F: FnOnce(ApiCommandResult) -> String,
+{
+    match engine.get_result(&process_id) {
+        Some(result) => f(result),
+        None => String::from("Result not available"),
+    }
+}
+
+/// Runs a queued `ApiCommand` to completion and wraps its output as the
+/// matching `ApiCommandResult` variant.
+pub async fn execute(command: ApiCommand) -> ApiCommandResult {
+    match command {
+        ApiCommand::SierraCompile {
+            version,
+            remix_file_path,
+            contract_path,
+        } => {
+            let response =
+                match do_compile_to_sierra(version.clone(), remix_file_path, contract_path).await
+                {
+                    Ok(response) => response.0,
+                    Err(e) => CompileResponse {
+                        file_content: "".to_string(),
+                        diagnostics: vec![],
+                        message: e,
+                        status: "CompilationFailed".to_string(),
+                        cairo_version: version,
+                    },
+                };
+            ApiCommandResult::SierraCompile(response)
+        }
+        ApiCommand::ScarbBuild {
+            version,
+            remix_file_path,
+            contract_path,
+        } => {
+            let response =
+                match do_scarb_build(version.clone(), remix_file_path, contract_path).await {
+                    Ok(response) => response.0,
+                    Err(e) => ArtifactsResponse {
+                        contracts: HashMap::new(),
+                        diagnostics: vec![],
+                        message: e,
+                        status: "CompilationFailed".to_string(),
+                        cairo_version: version,
+                    },
+                };
+            ApiCommandResult::ScarbBuild(response)
+        }
+        ApiCommand::Detect {
+            version,
+            remix_file_path,
+        } => {
+            let response = match do_detect(version.clone(), remix_file_path).await {
+                Ok(response) => response.0,
+                Err(e) => DetectResponse {
+                    findings: vec![],
+                    message: e,
+                    status: "DetectionFailed".to_string(),
+                    cairo_version: version,
+                },
+            };
+            ApiCommandResult::Detect(response)
+        }
+        ApiCommand::RunTests {
+            version,
+            remix_file_path,
+        } => {
+            let response = match do_run_tests(version.clone(), remix_file_path).await {
+                Ok(response) => response.0,
+                Err(e) => RunTestsResponse {
+                    tests: vec![],
+                    message: e,
+                    status: "TestRunFailed".to_string(),
+                    cairo_version: version,
+                },
+            };
+            ApiCommandResult::RunTests(response)
+        }
+    }
+}
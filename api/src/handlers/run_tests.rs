@@ -0,0 +1,307 @@
+use crate::handlers::process::{do_process_command, fetch_process_result};
+use crate::handlers::types::{ApiCommand, ApiCommandResult, RunTestsResponse, TestResult};
+use crate::utils::lib::{get_project_path, CAIRO_COMPILERS_DIR};
+use crate::worker::WorkerEngine;
+use rocket::serde::json;
+use rocket::serde::json::Json;
+use rocket::State;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use tracing::{debug, instrument};
+
+#[instrument]
+#[get("/run-tests/<version>/<remix_file_path..>")]
+pub async fn run_tests(version: String, remix_file_path: PathBuf) -> Json<RunTestsResponse> {
+    info!("/run-tests");
+
+    let res = do_run_tests(version.clone(), remix_file_path).await;
+
+    match res {
+        Ok(res) => res,
+        Err(e) => Json(RunTestsResponse {
+            tests: vec![],
+            message: e,
+            status: "TestRunFailed".to_string(),
+            cairo_version: version,
+        }),
+    }
+}
+
+#[instrument]
+#[get("/run-tests-async/<version>/<remix_file_path..>")]
+pub async fn run_tests_async(
+    version: String,
+    remix_file_path: PathBuf,
+    engine: &State<WorkerEngine>,
+) -> String {
+    info!("/run-tests-async");
+    do_process_command(
+        ApiCommand::RunTests {
+            version,
+            remix_file_path,
+        },
+        engine,
+    )
+}
+
+#[instrument]
+#[get("/run-tests-result/<process_id>")]
+pub async fn get_run_tests_result(process_id: String, engine: &State<WorkerEngine>) -> String {
+    info!("/run-tests-result");
+    fetch_process_result(process_id, engine, |result| match result {
+        ApiCommandResult::RunTests(tests_result) => json::to_string(&tests_result).unwrap(),
+        _ => String::from("Result not available"),
+    })
+}
+
+/// Compiles the file/project at `remix_file_path` with the pinned `version`
+/// of the toolchain, then runs its `#[test]` functions through the bundled
+/// `cairo-test` runner binary.
+pub async fn do_run_tests(
+    version: String,
+    remix_file_path: PathBuf,
+) -> Result<Json<RunTestsResponse>, String> {
+    let remix_file_path_str = match remix_file_path.to_str() {
+        Some(path) => path.to_string(),
+        None => {
+            return Ok(Json(RunTestsResponse {
+                tests: vec![],
+                message: "File path not found".to_string(),
+                status: "FileNotFound".to_string(),
+                cairo_version: version,
+            }));
+        }
+    };
+
+    let path_to_cairo_compiler = Path::new(CAIRO_COMPILERS_DIR).join(&version);
+    if !path_to_cairo_compiler.exists() {
+        return Err(format!("Cairo compiler with version {} not found", version));
+    }
+
+    let project_path = get_project_path(&remix_file_path_str);
+
+    let mut run = Command::new("cargo");
+    run.current_dir(&path_to_cairo_compiler);
+
+    let result = run
+        .arg("run")
+        .arg("--release")
+        .arg("--bin")
+        .arg("cairo-test")
+        .arg("--")
+        .arg(&project_path)
+        .stderr(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to execute cairo-test: {:?}", e))?;
+
+    debug!("LOG: ran command:{:?}", run);
+
+    let output = result.wait_with_output().expect("Failed to wait on child");
+
+    let stdout = String::from_utf8(output.stdout).unwrap_or_default();
+    let tests = parse_test_results(&stdout);
+
+    Ok(Json(RunTestsResponse {
+        tests,
+        message: String::from_utf8(output.stderr).unwrap_or_default(),
+        status: match output.status.code() {
+            Some(0) => "Success".to_string(),
+            Some(_) => "TestsFailed".to_string(),
+            None => "UnknownError".to_string(),
+        },
+        cairo_version: version,
+    }))
+}
+
+/// Parses `cairo-test`'s textual output into structured [`TestResult`]s.
+///
+/// Each test produces an inline line of the form
+/// `test <name> ... ok (gas usage est.: <n>)` or `test <name> ... fail`
+/// giving the name, pass/fail status and gas usage. Failure messages aren't
+/// inline, though: like the Rust test harness it's modeled on, `cairo-test`
+/// summarizes them in a trailing `failures:` section with one
+/// `---- <name> ----` block per failing test, so that section is parsed
+/// separately and merged back in by name.
+fn parse_test_results(stdout: &str) -> Vec<TestResult> {
+    let mut tests = Vec::new();
+
+    for line in stdout.lines() {
+        let Some(rest) = line.trim().strip_prefix("test ") else {
+            continue;
+        };
+        let Some((name, outcome)) = rest.split_once(" ... ") else {
+            continue;
+        };
+
+        let passed = outcome.starts_with("ok");
+        let gas_usage = outcome
+            .split("gas usage est.: ")
+            .nth(1)
+            .and_then(|s| s.trim_end_matches(')').parse::<u64>().ok());
+
+        tests.push(TestResult {
+            name: name.to_string(),
+            passed,
+            gas_usage,
+            failure_message: None,
+        });
+    }
+
+    if tests.iter().any(|t| !t.passed) {
+        let failure_messages = parse_failure_section(stdout);
+        for test in &mut tests {
+            if !test.passed {
+                test.failure_message = failure_messages.get(&test.name).cloned();
+            }
+        }
+    }
+
+    tests
+}
+
+/// Parses the trailing `failures:` section(s) of `cairo-test` output into a
+/// map of test name to failure message.
+///
+/// The detailed section looks like:
+/// ```text
+/// failures:
+///
+/// ---- pkg::test_bad ----
+/// Panicked with "assertion failed"
+///
+/// ---- pkg::test_other ----
+/// Panicked with "oops"
+///
+///
+/// failures:
+///     pkg::test_bad
+///     pkg::test_other
+/// ```
+/// Only the first, detailed section carries messages; the second is a bare
+/// name list and is harmlessly skipped since none of its lines match the
+/// `---- <name> ----` header.
+fn parse_failure_section(stdout: &str) -> HashMap<String, String> {
+    let mut messages = HashMap::new();
+    let mut lines = stdout.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        if line.trim() != "failures:" {
+            continue;
+        }
+
+        while let Some(next) = lines.peek() {
+            let trimmed = next.trim();
+            if trimmed.is_empty() {
+                lines.next();
+                continue;
+            }
+            let Some(name) = trimmed
+                .strip_prefix("---- ")
+                .and_then(|s| s.strip_suffix(" ----"))
+            else {
+                break;
+            };
+            lines.next();
+
+            let mut message_lines = Vec::new();
+            while let Some(body_line) = lines.peek() {
+                let body_trimmed = body_line.trim();
+                let starts_next_block = body_trimmed.is_empty()
+                    || (body_trimmed.starts_with("---- ") && body_trimmed.ends_with(" ----"));
+                if starts_next_block {
+                    break;
+                }
+                message_lines.push(body_trimmed.to_string());
+                lines.next();
+            }
+            messages.insert(name.to_string(), message_lines.join("\n"));
+        }
+    }
+
+    messages
+}
+
+#[cfg(test)]
+mod parse_test_results_tests {
+    use super::*;
+
+    const SAMPLE_OUTPUT: &str = "\
+running 3 tests
+test pkg::test_ok ... ok (gas usage est.: 1234)
+test pkg::test_bad ... fail
+test pkg::test_other ... fail
+test result: FAILED. 1 passed; 2 failed; 0 ignored; 0 filtered out;
+
+failures:
+
+---- pkg::test_bad ----
+Panicked with \"assertion failed\"
+
+---- pkg::test_other ----
+Panicked with \"oops\"
+
+
+failures:
+    pkg::test_bad
+    pkg::test_other
+
+test result: FAILED. 1 passed; 2 failed; 0 ignored; 0 filtered out;
+";
+
+    #[test]
+    fn parses_inline_name_status_and_gas_usage() {
+        let tests = parse_test_results(SAMPLE_OUTPUT);
+        assert_eq!(tests.len(), 3);
+        assert_eq!(tests[0].name, "pkg::test_ok");
+        assert!(tests[0].passed);
+        assert_eq!(tests[0].gas_usage, Some(1234));
+    }
+
+    #[test]
+    fn merges_failure_messages_from_trailing_section() {
+        let tests = parse_test_results(SAMPLE_OUTPUT);
+        let bad = tests.iter().find(|t| t.name == "pkg::test_bad").unwrap();
+        assert_eq!(
+            bad.failure_message.as_deref(),
+            Some("Panicked with \"assertion failed\"")
+        );
+        let other = tests.iter().find(|t| t.name == "pkg::test_other").unwrap();
+        assert_eq!(other.failure_message.as_deref(), Some("Panicked with \"oops\""));
+    }
+
+    #[test]
+    fn passing_tests_have_no_failure_message() {
+        let tests = parse_test_results(SAMPLE_OUTPUT);
+        let ok = tests.iter().find(|t| t.name == "pkg::test_ok").unwrap();
+        assert_eq!(ok.failure_message, None);
+    }
+
+    #[test]
+    fn summary_line_is_not_mistaken_for_a_test_line() {
+        let tests = parse_test_results(SAMPLE_OUTPUT);
+        assert!(tests.iter().all(|t| t.name != "result:"));
+    }
+
+    #[test]
+    fn failure_blocks_without_a_blank_separator_do_not_bleed_into_each_other() {
+        let output = "\
+test pkg::test_bad ... fail
+test pkg::test_other ... fail
+test result: FAILED. 0 passed; 2 failed; 0 ignored; 0 filtered out;
+
+failures:
+
+---- pkg::test_bad ----
+Panicked with \"x\"
+---- pkg::test_other ----
+Panicked with \"y\"
+";
+        let tests = parse_test_results(output);
+        let bad = tests.iter().find(|t| t.name == "pkg::test_bad").unwrap();
+        assert_eq!(bad.failure_message.as_deref(), Some("Panicked with \"x\""));
+        let other = tests.iter().find(|t| t.name == "pkg::test_other").unwrap();
+        assert_eq!(other.failure_message.as_deref(), Some("Panicked with \"y\""));
+    }
+}
@@ -0,0 +1,148 @@
+use rocket::serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone)]
+pub enum ApiCommand {
+    SierraCompile {
+        version: String,
+        remix_file_path: PathBuf,
+        contract_path: Option<String>,
+    },
+    ScarbBuild {
+        version: String,
+        remix_file_path: PathBuf,
+        contract_path: Option<String>,
+    },
+    Detect {
+        version: String,
+        remix_file_path: PathBuf,
+    },
+    RunTests {
+        version: String,
+        remix_file_path: PathBuf,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum ApiCommandResult {
+    SierraCompile(CompileResponse),
+    ScarbBuild(ArtifactsResponse),
+    Detect(DetectResponse),
+    RunTests(RunTestsResponse),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(crate = "rocket::serde")]
+pub struct CompileResponse {
+    pub file_content: String,
+    /// Structured form of the diagnostics folded into `message`, so the
+    /// front end can underline exact ranges instead of parsing raw text.
+    pub diagnostics: Vec<Diagnostic>,
+    pub message: String,
+    pub status: String,
+    pub cairo_version: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "rocket::serde")]
+pub enum DiagnosticSeverity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[serde(crate = "rocket::serde")]
+pub struct Span {
+    pub start_line: u32,
+    pub start_col: u32,
+    pub end_line: u32,
+    pub end_col: u32,
+}
+
+/// A single compiler diagnostic, kept alongside the raw `message` string for
+/// backward compatibility.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(crate = "rocket::serde")]
+pub struct Diagnostic {
+    pub severity: DiagnosticSeverity,
+    pub message: String,
+    pub remix_file_path: String,
+    pub span: Option<Span>,
+}
+
+/// A single contract's build output.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(crate = "rocket::serde")]
+pub struct ContractArtifact {
+    pub sierra: String,
+    pub casm: Option<String>,
+}
+
+/// Every contract a build produced. A `scarb build` keys each entry by its
+/// fully-qualified contract path (e.g. `token::myerc20::ERC20`); the
+/// `starknet-compile` fallback produces a single entry keyed by the Remix
+/// file's stem, not by a module path. Narrowed to a single entry when the
+/// caller passed a `contract_path` query parameter.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(crate = "rocket::serde")]
+pub struct ArtifactsResponse {
+    pub contracts: HashMap<String, ContractArtifact>,
+    /// Structured compiler diagnostics, same as `CompileResponse::diagnostics`.
+    /// Only populated by the non-Scarb `starknet-compile` fallback; `scarb
+    /// build`'s own diagnostics aren't in a format `parse_diagnostics`
+    /// understands, so this is empty on that path.
+    pub diagnostics: Vec<Diagnostic>,
+    pub message: String,
+    pub status: String,
+    pub cairo_version: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(crate = "rocket::serde")]
+pub enum Severity {
+    Info,
+    Low,
+    Medium,
+    High,
+}
+
+/// A single static-analysis finding produced by a detector.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(crate = "rocket::serde")]
+pub struct Finding {
+    pub detector_id: String,
+    pub severity: Severity,
+    pub title: String,
+    pub contract_path: String,
+    pub function: String,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(crate = "rocket::serde")]
+pub struct DetectResponse {
+    pub findings: Vec<Finding>,
+    pub message: String,
+    pub status: String,
+    pub cairo_version: String,
+}
+
+/// Outcome of a single `#[test]` function.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(crate = "rocket::serde")]
+pub struct TestResult {
+    pub name: String,
+    pub passed: bool,
+    pub gas_usage: Option<u64>,
+    pub failure_message: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(crate = "rocket::serde")]
+pub struct RunTestsResponse {
+    pub tests: Vec<TestResult>,
+    pub message: String,
+    pub status: String,
+    pub cairo_version: String,
+}
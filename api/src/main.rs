@@ -0,0 +1,39 @@
+#[macro_use]
+extern crate rocket;
+#[macro_use]
+extern crate tracing;
+
+mod handlers;
+mod utils;
+mod worker;
+
+use handlers::compile_sierra::{
+    compile_to_siera_async, compile_to_sierra, get_scarb_build_result, get_siera_compile_result,
+    scarb_build, scarb_build_async,
+};
+use handlers::detect::{detect, detect_async, get_detect_result};
+use handlers::run_tests::{get_run_tests_result, run_tests, run_tests_async};
+use worker::WorkerEngine;
+
+#[launch]
+fn rocket() -> _ {
+    rocket::build()
+        .manage(WorkerEngine::new())
+        .mount(
+            "/",
+            routes![
+                compile_to_sierra,
+                compile_to_siera_async,
+                get_siera_compile_result,
+                scarb_build,
+                scarb_build_async,
+                get_scarb_build_result,
+                detect,
+                detect_async,
+                get_detect_result,
+                run_tests,
+                run_tests_async,
+                get_run_tests_result,
+            ],
+        )
+}
@@ -0,0 +1,31 @@
+use std::path::{Path, PathBuf};
+
+/// Root directory under which per-session Remix workspaces are persisted on disk.
+pub const SIERRA_ROOT: &str = "/tmp/remix-sierra";
+
+/// Root directory under which the pinned Cairo compiler toolchains are checked out,
+/// one subdirectory per `version`.
+pub const CAIRO_COMPILERS_DIR: &str = "/cairo-compilers";
+
+/// Returns the extension of a file path, or an empty string if it has none.
+pub fn get_file_ext(file_path: &str) -> String {
+    Path::new(file_path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Resolves a Remix-relative file path to its location on disk under `SIERRA_ROOT`.
+pub fn get_file_path(remix_file_path: &str) -> PathBuf {
+    Path::new(SIERRA_ROOT).join(remix_file_path)
+}
+
+/// Resolves a Remix-relative path to the directory on disk that contains it,
+/// i.e. the project root that is handed to `scarb`, `starknet-compile` or `cairo-test`.
+pub fn get_project_path(remix_file_path: &str) -> PathBuf {
+    match get_file_path(remix_file_path).parent() {
+        Some(parent) => parent.to_path_buf(),
+        None => Path::new(SIERRA_ROOT).to_path_buf(),
+    }
+}
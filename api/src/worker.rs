@@ -0,0 +1,42 @@
+use crate::handlers::process::execute;
+use crate::handlers::types::{ApiCommand, ApiCommandResult};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// Dispatches long-running `ApiCommand`s onto background tasks and keeps their
+/// results around until the caller polls for them via a `process_id`.
+pub struct WorkerEngine {
+    results: Arc<Mutex<HashMap<String, ApiCommandResult>>>,
+}
+
+impl WorkerEngine {
+    pub fn new() -> Self {
+        Self {
+            results: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Queues `command` for execution on a background task and immediately
+    /// returns the `process_id` its result will later be stored under.
+    pub fn add_command(&self, command: ApiCommand) -> String {
+        let process_id = Uuid::new_v4().to_string();
+        let results = self.results.clone();
+        let pid = process_id.clone();
+        rocket::tokio::spawn(async move {
+            let result = execute(command).await;
+            results.lock().unwrap().insert(pid, result);
+        });
+        process_id
+    }
+
+    pub fn get_result(&self, process_id: &str) -> Option<ApiCommandResult> {
+        self.results.lock().unwrap().remove(process_id)
+    }
+}
+
+impl Default for WorkerEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}